@@ -65,8 +65,7 @@ impl ProtocolCommands<MysticetiBenchmarkType> for MysticetiProtocol {
     }
 
     fn db_directories(&self) -> Vec<PathBuf> {
-        // TODO
-        vec![]
+        vec![self.working_dir.clone()]
     }
 
     fn genesis_command<'a, I>(&self, instances: I) -> String
@@ -145,7 +144,8 @@ impl ProtocolCommands<MysticetiBenchmarkType> for MysticetiProtocol {
     where
         I: IntoIterator<Item = Instance>,
     {
-        // TODO
+        // Mysticeti validators generate their own synthetic load; there is no
+        // separate client process to launch.
         vec![]
     }
 }
@@ -0,0 +1,34 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runtime configuration for the v2 consensus node.
+
+use serde::{Deserialize, Serialize};
+
+/// Port on which [`crate::v2::metrics::spawn_metrics_server`] serves the
+/// node's Prometheus metrics. Must match `ProtocolMetrics::NODE_METRICS_PORT`
+/// in the benchmark orchestrator, which scrapes this port.
+pub const NODE_METRICS_PORT: u16 = 9091;
+
+/// Operator-tunable knobs for a v2 consensus node, loaded from the node's
+/// configuration file alongside the committee and private config.
+///
+/// Note: the file/CLI loading path that produces this struct from a
+/// `--parameters-path` (see `MysticetiProtocol::node_command` in the
+/// orchestrator) lives outside this module; nothing here does that parsing
+/// itself, `NetworkSyncer::start` just takes one by reference.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Parameters {
+    /// How much idle time [`crate::v2::net_sync::NetworkSyncer::send_blocks`]
+    /// spends between batches relative to how long the previous batches took
+    /// to send: 0.0 disables throttling, 1.0 spends as much time idle as
+    /// busy. Lets operators trade dissemination latency against resource
+    /// usage.
+    pub tranquility: f64,
+}
+
+impl Default for Parameters {
+    fn default() -> Self {
+        Self { tranquility: 0.0 }
+    }
+}
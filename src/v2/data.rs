@@ -1,9 +1,12 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use blake2::digest::consts::U32;
+use blake2::{Blake2b, Digest as _};
 use serde::de::{DeserializeOwned, Error};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::sync::Arc;
 
@@ -21,12 +24,72 @@ pub struct Data<T>(Arc<DataInner<T>>);
 struct DataInner<T> {
     t: T,
     serialized: Vec<u8>, // this is serialized as bincode regardless of underlining serialization
+    digest: Digest,
+}
+
+/// Stable content identifier for a [`Data<T>`], derived from its canonical
+/// bincode encoding. Because `serialized` is the single canonical encoding
+/// regardless of the outer wire format, the digest is stable across wire
+/// round-trips, so it can be used to key blocks in maps/sets by identity
+/// without dereferencing and re-hashing the full payload.
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Digest([u8; 32]);
+
+impl Digest {
+    fn new(bytes: &[u8]) -> Self {
+        let mut hasher = Blake2b::<U32>::new();
+        hasher.update(bytes);
+        let mut digest = [0u8; 32];
+        digest.copy_from_slice(&hasher.finalize());
+        Self(digest)
+    }
+}
+
+impl fmt::Debug for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for byte in &self.0[..4] {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
 }
 
 impl<T: Serialize> Data<T> {
     pub fn new(t: T) -> Self {
         let serialized = bincode::serialize(&t).expect("Serialization should not fail");
-        Self(Arc::new(DataInner { t, serialized }))
+        let digest = Digest::new(&serialized);
+        Self(Arc::new(DataInner {
+            t,
+            serialized,
+            digest,
+        }))
+    }
+}
+
+impl<T> Data<T> {
+    /// Size in bytes of the cached bincode-serialized representation, without
+    /// re-serializing the value.
+    pub fn serialized_size(&self) -> usize {
+        self.0.serialized.len()
+    }
+
+    /// Stable content digest of the cached bincode-serialized representation.
+    pub fn digest(&self) -> &Digest {
+        &self.0.digest
+    }
+}
+
+impl<T> PartialEq for Data<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.digest == other.0.digest
+    }
+}
+
+impl<T> Eq for Data<T> {}
+
+impl<T> Hash for Data<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.digest.hash(state);
     }
 }
 
@@ -56,7 +119,12 @@ impl<'de, T: DeserializeOwned> Deserialize<'de> for Data<T> {
         let Ok(t) = bincode::deserialize(&serialized) else {
             return Err(D::Error::custom("Failed to deserialized inner bytes"));
         };
-        Ok(Self(Arc::new(DataInner { t, serialized })))
+        let digest = Digest::new(&serialized);
+        Ok(Self(Arc::new(DataInner {
+            t,
+            serialized,
+            digest,
+        })))
     }
 }
 
@@ -70,4 +138,29 @@ impl<T: fmt::Display> fmt::Display for Data<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.t.fmt(f)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equality_and_hash_are_content_based() {
+        let a = Data::new(vec![1u8, 2, 3]);
+        let b = Data::new(vec![1u8, 2, 3]);
+        let c = Data::new(vec![4u8, 5, 6]);
+        assert_eq!(a, b);
+        assert_eq!(a.digest(), b.digest());
+        assert_ne!(a, c);
+        assert_ne!(a.digest(), c.digest());
+    }
+
+    #[test]
+    fn digest_is_stable_across_a_wire_round_trip() {
+        let original = Data::new(42u32);
+        let bytes = bincode::serialize(&original).expect("serialize");
+        let restored: Data<u32> = bincode::deserialize(&bytes).expect("deserialize");
+        assert_eq!(original, restored);
+        assert_eq!(original.digest(), restored.digest());
+    }
 }
\ No newline at end of file
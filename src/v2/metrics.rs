@@ -0,0 +1,139 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Prometheus metrics for [`crate::v2::net_sync::NetworkSyncer`]. Scraped by
+//! both the benchmark orchestrator and real deployments on `NODE_METRICS_PORT`.
+
+use prometheus::{
+    register_counter_with_registry, register_gauge_with_registry,
+    register_histogram_with_registry, register_int_counter_vec_with_registry,
+    register_int_counter_with_registry, register_int_gauge_with_registry, Counter, Encoder, Gauge,
+    Histogram, IntCounter, IntCounterVec, IntGauge, Registry, TextEncoder,
+};
+use std::net::SocketAddr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+pub struct NetworkMetrics {
+    pub blocks_sent: IntCounterVec,
+    pub blocks_received: IntCounterVec,
+    pub bytes_disseminated: IntCounter,
+    pub active_subscriptions: IntGauge,
+    pub leader_timeouts: IntCounter,
+    pub handshake_rejections: IntCounterVec,
+    /// Per-block commit latency. Named to match what
+    /// `ProtocolMetrics::LATENCY_BUCKETS`/`LATENCY_SUM`/`TOTAL_TRANSACTIONS`
+    /// in the orchestrator already scrape (`latency_s`, `latency_s_sum`,
+    /// `latency_s_count`).
+    pub latency_s: Histogram,
+    /// Matches `ProtocolMetrics::LATENCY_SQUARED_SUM` ("latency_squared_s"):
+    /// running sum of squared per-block commit latencies, used by the
+    /// orchestrator to compute a standard deviation across the fleet.
+    pub latency_squared_s: Counter,
+    /// Matches `ProtocolMetrics::BENCHMARK_DURATION` ("benchmark_duration"):
+    /// wall-clock seconds since this node started disseminating blocks.
+    pub benchmark_duration: Gauge,
+}
+
+impl NetworkMetrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            blocks_sent: register_int_counter_vec_with_registry!(
+                "blocks_sent_total",
+                "Number of blocks sent to each peer",
+                &["peer"],
+                registry,
+            )
+            .unwrap(),
+            blocks_received: register_int_counter_vec_with_registry!(
+                "blocks_received_total",
+                "Number of blocks received from each peer",
+                &["peer"],
+                registry,
+            )
+            .unwrap(),
+            bytes_disseminated: register_int_counter_with_registry!(
+                "bytes_disseminated_total",
+                "Total bytes of block data sent to peers",
+                registry,
+            )
+            .unwrap(),
+            active_subscriptions: register_int_gauge_with_registry!(
+                "active_subscriptions",
+                "Number of peers currently subscribed to our block stream",
+                registry,
+            )
+            .unwrap(),
+            leader_timeouts: register_int_counter_with_registry!(
+                "leader_timeouts_total",
+                "Number of times the leader timeout fired and forced a new block",
+                registry,
+            )
+            .unwrap(),
+            handshake_rejections: register_int_counter_vec_with_registry!(
+                "handshake_rejections_total",
+                "Number of incoming connections rejected during the Hello handshake, by reason",
+                &["reason"],
+                registry,
+            )
+            .unwrap(),
+            latency_s: register_histogram_with_registry!(
+                "latency_s",
+                "Time between a block being created and it being committed",
+                registry,
+            )
+            .unwrap(),
+            latency_squared_s: register_counter_with_registry!(
+                "latency_squared_s",
+                "Running sum of squared per-block commit latencies, in seconds squared",
+                registry,
+            )
+            .unwrap(),
+            benchmark_duration: register_gauge_with_registry!(
+                "benchmark_duration",
+                "Wall-clock seconds since this node started disseminating blocks",
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+}
+
+/// Serve `registry` as a Prometheus text-exposition endpoint on `address`.
+///
+/// This is a deliberately minimal HTTP/1.1 responder: the only client is
+/// Prometheus scraping a single route, so there is no need to pull in a full
+/// HTTP server stack.
+pub fn spawn_metrics_server(address: SocketAddr, registry: Registry) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let listener = match TcpListener::bind(address).await {
+            Ok(listener) => listener,
+            Err(error) => {
+                eprintln!("Failed to bind metrics endpoint on {address}: {error}");
+                return;
+            }
+        };
+        loop {
+            let Ok((mut socket, _)) = listener.accept().await else {
+                continue;
+            };
+            let registry = registry.clone();
+            tokio::spawn(async move {
+                let mut buf = [0u8; 1024];
+                let _ = socket.read(&mut buf).await;
+                let metric_families = registry.gather();
+                let mut body = Vec::new();
+                TextEncoder::new()
+                    .encode(&metric_families, &mut body)
+                    .unwrap();
+                let header = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\n\r\n",
+                    body.len()
+                );
+                let _ = socket.write_all(header.as_bytes()).await;
+                let _ = socket.write_all(&body).await;
+            });
+        }
+    })
+}
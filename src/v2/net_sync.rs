@@ -1,55 +1,171 @@
 use crate::v2::block_handler::BlockHandler;
+use crate::v2::config::{Parameters, NODE_METRICS_PORT};
 use crate::v2::core::Core;
+use crate::v2::data::Data;
+use crate::v2::metrics::{spawn_metrics_server, NetworkMetrics};
 use crate::v2::network::{Connection, Network, NetworkMessage};
 use crate::v2::syncer::{CommitObserver, Syncer, SyncerSignals};
-use crate::v2::types::{AuthorityIndex, RoundNumber};
-use futures::future::join_all;
+use crate::v2::types::{AuthorityIndex, RoundNumber, StatementBlock};
 use parking_lot::RwLock;
-use std::collections::HashMap;
+use prometheus::Registry;
+use std::collections::{HashMap, VecDeque};
+use std::net::SocketAddr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant, SystemTime};
 use tokio::runtime::Handle;
 use tokio::select;
+use tokio::signal::unix::{signal, SignalKind};
 use tokio::sync::{mpsc, Notify};
 use tokio::task::JoinHandle;
 
+/// Number of blocks fetched per `get_own_blocks` call in [`NetworkSyncer::send_blocks`].
+const BATCH_SIZE: usize = 10;
+
+/// Single-byte wire version exchanged via `NetworkMessage::Hello` before any
+/// other handshake data. Bump this whenever a wire-incompatible change is
+/// made: a mismatched peer is rejected as soon as this one byte is read,
+/// instead of attempting to parse committee/authority fields it may encode
+/// differently and failing with an opaque deserialization error.
+const WIRE_VERSION: u8 = 1;
+
 pub struct NetworkSyncer<H: BlockHandler, C: CommitObserver> {
     inner: Arc<NetworkSyncerInner<H, C>>,
     main_task: JoinHandle<()>,
     stop: mpsc::Receiver<()>,
+    control: mpsc::Sender<ControlMessage>,
 }
 
 struct NetworkSyncerInner<H: BlockHandler, C: CommitObserver> {
-    syncer: RwLock<Syncer<H, Arc<Notify>, C>>,
+    syncer: RwLock<Syncer<H, Arc<Notify>, MetricsCommitObserver<C>>>,
     notify: Arc<Notify>,
     stop: mpsc::Sender<()>,
+    status: RwLock<NetworkStatus>,
+    /// Copied from `Parameters::tranquility` at `start()` time: how much idle
+    /// time [`NetworkSyncer::send_blocks`] spends between batches relative to
+    /// how long the previous batches took to send (0.0 disables throttling,
+    /// 1.0 spends as much time idle as busy).
+    tranquility: f64,
+    metrics: Arc<NetworkMetrics>,
+}
+
+/// Whether the network task is actively disseminating/accepting blocks, or
+/// parked by [`NetworkSyncer::pause`] while the underlying `Syncer` is kept
+/// intact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum NetworkStatus {
+    Running,
+    Stopped,
+}
+
+/// Commands accepted by the [`NetworkSyncer::run`] loop to pause/resume gossip
+/// without tearing down the `Syncer`, committee, or stored blocks.
+enum ControlMessage {
+    StartNetwork,
+    StopNetwork,
+}
+
+/// A connection's own task plus a handle to whichever `send_blocks` task it
+/// has most recently spawned for a subscription. `connection_task` only
+/// drains the latter cooperatively on its own cleanup path; aborting just
+/// `task` (e.g. from [`NetworkSyncer::run`]'s `StopNetwork` handling) would
+/// leave that child task running forever, still holding an
+/// `Arc<NetworkSyncerInner>`. `abort_and_join` tears down both.
+struct ConnectionHandle {
+    task: JoinHandle<Option<()>>,
+    subscribe_handler: Arc<RwLock<Option<JoinHandle<Option<()>>>>>,
+}
+
+impl ConnectionHandle {
+    async fn abort_and_join(self, metrics: &NetworkMetrics) {
+        if let Some(handler) = self.subscribe_handler.write().take() {
+            metrics.active_subscriptions.dec();
+            handler.abort();
+            handler.await.ok();
+        }
+        self.task.abort();
+        self.task.await.ok();
+    }
 }
 
 impl<H: BlockHandler + 'static, C: CommitObserver + 'static> NetworkSyncer<H, C> {
-    pub fn start(network: Network, core: Core<H>, commit_period: u64, commit_observer: C) -> Self {
+    pub fn start(
+        network: Network,
+        core: Core<H>,
+        commit_period: u64,
+        commit_observer: C,
+        parameters: &Parameters,
+    ) -> Self {
         let handle = Handle::current();
         let notify = Arc::new(Notify::new());
+        let registry = Registry::new();
+        let metrics = Arc::new(NetworkMetrics::new(&registry));
+        spawn_metrics_server(SocketAddr::from(([0, 0, 0, 0], NODE_METRICS_PORT)), registry);
+        let commit_observer = MetricsCommitObserver {
+            inner: commit_observer,
+            metrics: metrics.clone(),
+        };
         let mut syncer = Syncer::new(core, commit_period, notify.clone(), commit_observer);
         syncer.force_new_block(0);
         let syncer = RwLock::new(syncer);
         let (stop_sender, stop_receiver) = mpsc::channel(1);
         stop_sender.try_send(()).unwrap(); // occupy the only available permit, so that all other calls to send() will block
+        let (control_sender, control_receiver) = mpsc::channel(8);
         let inner = Arc::new(NetworkSyncerInner {
             notify,
             syncer,
             stop: stop_sender,
+            status: RwLock::new(NetworkStatus::Running),
+            tranquility: parameters.tranquility,
+            metrics,
         });
-        let main_task = handle.spawn(Self::run(network, inner.clone()));
+        let main_task = handle.spawn(Self::run(network, control_receiver, inner.clone()));
         Self {
             inner,
             main_task,
             stop: stop_receiver,
+            control: control_sender,
         }
     }
 
-    pub async fn shutdown(self) -> Syncer<H, Arc<Notify>, C> {
+    /// Pause participation in block dissemination while keeping the `Syncer`
+    /// state, committee, and stored blocks intact. Live connections are
+    /// dropped; connections that arrive while paused are queued until
+    /// [`Self::resume`] is called.
+    pub fn pause(&self) {
+        self.control.try_send(ControlMessage::StopNetwork).ok();
+    }
+
+    /// Resume block dissemination after a [`Self::pause`], re-subscribing to
+    /// every connection that was queued while stopped.
+    pub fn resume(&self) {
+        self.control.try_send(ControlMessage::StartNetwork).ok();
+    }
+
+    /// Wait for SIGINT or SIGTERM, then [`Self::shutdown`] so a validator
+    /// terminates cleanly instead of dropping subscribers mid-stream. This is
+    /// what the node binary should await instead of calling `shutdown()`
+    /// directly.
+    pub async fn shutdown_on_signal(self) -> Syncer<H, Arc<Notify>, MetricsCommitObserver<C>> {
+        let mut sigterm = signal(SignalKind::terminate())
+            .expect("Failed to install SIGTERM handler");
+        select! {
+            _ctrl_c = tokio::signal::ctrl_c() => {}
+            _sigterm = sigterm.recv() => {}
+        }
+        self.shutdown().await
+    }
+
+    /// Drain the network and return the inner `Syncer`. Prefer
+    /// [`Self::shutdown_on_signal`] in the node binary so a validator drains
+    /// in response to an actual SIGINT/SIGTERM rather than needing a caller
+    /// to invoke this directly.
+    pub async fn shutdown(self) -> Syncer<H, Arc<Notify>, MetricsCommitObserver<C>> {
         drop(self.stop);
-        // todo - wait for network shutdown as well
+        drop(self.control);
+        // `run` stops accepting new connections as soon as the stop signal is
+        // observed and joins every connection/leader-timeout task before
+        // returning, which is what guarantees every `Arc<NetworkSyncerInner>`
+        // clone below is dropped before we try to unwrap it.
         self.main_task.await.ok();
         let Ok(inner) = Arc::try_unwrap(self.inner) else {
             panic!("Shutdown failed - not all resources are freed after main task is compelted");
@@ -57,31 +173,92 @@ impl<H: BlockHandler + 'static, C: CommitObserver + 'static> NetworkSyncer<H, C>
         inner.syncer.into_inner()
     }
 
-    async fn run(mut network: Network, inner: Arc<NetworkSyncerInner<H, C>>) {
-        let mut connections: HashMap<usize, JoinHandle<Option<()>>> = HashMap::new();
+    async fn run(
+        mut network: Network,
+        mut control: mpsc::Receiver<ControlMessage>,
+        inner: Arc<NetworkSyncerInner<H, C>>,
+    ) {
+        let mut connections: HashMap<usize, ConnectionHandle> = HashMap::new();
+        let mut pending_connections: Vec<Connection> = Vec::new();
         let handle = Handle::current();
-        let leader_timeout_task = handle.spawn(Self::leader_timeout_task(inner.clone()));
-        while let Some(connection) = inner.recv_or_stopped(network.connection_receiver()).await {
-            let peer_id = connection.peer_id;
-            if let Some(task) = connections.remove(&peer_id) {
-                // wait until previous sync task completes
-                task.await.ok();
+        let mut leader_timeout_task = Some(handle.spawn(Self::leader_timeout_task(inner.clone())));
+        let benchmark_duration_task = handle.spawn(Self::benchmark_duration_task(inner.clone()));
+        loop {
+            select! {
+                connection = inner.recv_or_stopped(network.connection_receiver()) => {
+                    let Some(connection) = connection else { break };
+                    if *inner.status.read() == NetworkStatus::Stopped {
+                        pending_connections.push(connection);
+                        continue;
+                    }
+                    Self::spawn_connection(connection, &inner, &handle, &mut connections).await;
+                }
+                command = control.recv() => {
+                    match command {
+                        Some(ControlMessage::StopNetwork) => {
+                            *inner.status.write() = NetworkStatus::Stopped;
+                            if let Some(task) = leader_timeout_task.take() {
+                                task.abort();
+                                task.await.ok();
+                            }
+                            for (_, handle) in connections.drain() {
+                                handle.abort_and_join(&inner.metrics).await;
+                            }
+                        }
+                        Some(ControlMessage::StartNetwork) => {
+                            *inner.status.write() = NetworkStatus::Running;
+                            leader_timeout_task
+                                .get_or_insert_with(|| handle.spawn(Self::leader_timeout_task(inner.clone())));
+                            for connection in pending_connections.drain(..) {
+                                Self::spawn_connection(connection, &inner, &handle, &mut connections).await;
+                            }
+                        }
+                        None => {}
+                    }
+                }
             }
-            let task = handle.spawn(Self::connection_task(connection, inner.clone()));
-            connections.insert(peer_id, task);
         }
-        join_all(
-            connections
-                .into_values()
-                .chain([leader_timeout_task].into_iter()),
-        )
-        .await;
+        for handle in connections.into_values() {
+            handle.abort_and_join(&inner.metrics).await;
+        }
+        if let Some(task) = leader_timeout_task {
+            task.await.ok();
+        }
+        benchmark_duration_task.await.ok();
+    }
+
+    async fn spawn_connection(
+        connection: Connection,
+        inner: &Arc<NetworkSyncerInner<H, C>>,
+        handle: &Handle,
+        connections: &mut HashMap<usize, ConnectionHandle>,
+    ) {
+        let peer_id = connection.peer_id;
+        if let Some(prior) = connections.remove(&peer_id) {
+            // wait until previous sync task completes
+            prior.abort_and_join(&inner.metrics).await;
+        }
+        let subscribe_handler = Arc::new(RwLock::new(None));
+        let task = handle.spawn(Self::connection_task(
+            connection,
+            inner.clone(),
+            subscribe_handler.clone(),
+        ));
+        connections.insert(
+            peer_id,
+            ConnectionHandle {
+                task,
+                subscribe_handler,
+            },
+        );
     }
 
     async fn connection_task(
         mut connection: Connection,
         inner: Arc<NetworkSyncerInner<H, C>>,
+        subscribe_handler: Arc<RwLock<Option<JoinHandle<Option<()>>>>>,
     ) -> Option<()> {
+        Self::handshake(&mut connection, &inner).await?;
         let last_seen = inner
             .syncer
             .read()
@@ -92,47 +269,224 @@ impl<H: BlockHandler + 'static, C: CommitObserver + 'static> NetworkSyncer<H, C>
             .await
             .ok()?;
         let handle = Handle::current();
-        let mut subscribe_handler: Option<JoinHandle<Option<()>>> = None;
         while let Some(message) = inner.recv_or_stopped(&mut connection.receiver).await {
             match message {
                 NetworkMessage::SubscribeOwnFrom(round) => {
-                    eprintln!("sub({round})");
-                    if let Some(send_blocks_handler) = subscribe_handler.take() {
-                        send_blocks_handler.abort();
-                        send_blocks_handler.await.ok();
+                    if subscribe_handler.read().is_none() {
+                        inner.metrics.active_subscriptions.inc();
+                    }
+                    if let Some(send_blocks_handler) = subscribe_handler.write().take() {
+                        Self::drain_subscribe_handler(send_blocks_handler).await;
                     }
-                    subscribe_handler = Some(handle.spawn(Self::send_blocks(
+                    let handler = handle.spawn(Self::send_blocks(
                         connection.sender.clone(),
                         inner.clone(),
                         round,
-                    )));
+                        connection.peer_id.to_string(),
+                    ));
+                    *subscribe_handler.write() = Some(handler);
                 }
                 NetworkMessage::Block(block) => {
-                    eprintln!("block({block})");
-                    inner.syncer.write().add_blocks(vec![block]);
+                    inner
+                        .metrics
+                        .blocks_received
+                        .with_label_values(&[&connection.peer_id.to_string()])
+                        .inc();
+                    let missing = inner.syncer.write().add_blocks(vec![block]);
+                    for missing_reference in missing {
+                        let from_round = inner
+                            .syncer
+                            .read()
+                            .last_seen_by_authority(missing_reference.authority);
+                        connection
+                            .sender
+                            .send(NetworkMessage::RequestBlocks {
+                                authority: missing_reference.authority,
+                                from_round,
+                                to_round: missing_reference.round,
+                            })
+                            .await
+                            .ok()?;
+                    }
+                }
+                NetworkMessage::RequestBlocks {
+                    authority,
+                    from_round,
+                    to_round,
+                } => {
+                    let blocks = inner
+                        .syncer
+                        .read()
+                        .get_blocks_by_authority(authority, from_round, to_round);
+                    connection
+                        .sender
+                        .send(NetworkMessage::BlocksResponse(blocks))
+                        .await
+                        .ok()?;
+                }
+                NetworkMessage::BlocksResponse(blocks) => {
+                    inner.syncer.write().add_blocks(blocks);
+                }
+                NetworkMessage::Hello(_) | NetworkMessage::HelloDetails { .. } => {
+                    // Hello/HelloDetails are only valid as the handshake that
+                    // precedes this loop; seeing either again here means the
+                    // peer is violating the handshake protocol.
+                    inner
+                        .metrics
+                        .handshake_rejections
+                        .with_label_values(&["hello_after_handshake"])
+                        .inc();
+                    return None;
                 }
             }
         }
-        if let Some(subscribe_handler) = subscribe_handler.take() {
-            subscribe_handler.abort();
-            subscribe_handler.await.ok();
+        if let Some(handler) = subscribe_handler.write().take() {
+            inner.metrics.active_subscriptions.dec();
+            Self::drain_subscribe_handler(handler).await;
         }
         None
     }
 
+    /// Give a `send_blocks` task a bounded grace period to flush any `Block`
+    /// message already queued in `connection.sender` before forcing it to
+    /// stop, rather than aborting it outright and dropping in-flight data.
+    /// On a full shutdown `send_blocks` now exits on its own as soon as
+    /// `inner.stopped()` resolves, so this almost always returns well before
+    /// the grace period elapses; the timeout only bites on a bare resubscribe
+    /// (no stop signal involved), where there is no signal for the *previous*
+    /// send_blocks task to exit on besides being replaced.
+    async fn drain_subscribe_handler(mut handler: JoinHandle<Option<()>>) {
+        let grace_period = Duration::from_millis(500);
+        if tokio::time::timeout(grace_period, &mut handler)
+            .await
+            .is_err()
+        {
+            handler.abort();
+            handler.await.ok();
+        }
+    }
+
+    /// Exchange and validate a handshake before any `SubscribeOwnFrom`/`Block`
+    /// traffic is allowed on `connection`. The handshake is two messages, not
+    /// one: a bare [`NetworkMessage::Hello`] carrying just [`WIRE_VERSION`] as
+    /// a single byte, then (once that is validated) a
+    /// [`NetworkMessage::HelloDetails`] carrying the committee epoch and
+    /// authority. This way an incompatible peer is rejected on the first byte
+    /// instead of having to be fully deserialized as part of a larger struct
+    /// it may encode differently. Closes the connection (returning `None`) on
+    /// any mismatch, rather than letting mismatched traffic through to be
+    /// misparsed or treated as equivocation.
+    async fn handshake(connection: &mut Connection, inner: &Arc<NetworkSyncerInner<H, C>>) -> Option<()> {
+        connection
+            .sender
+            .send(NetworkMessage::Hello(WIRE_VERSION))
+            .await
+            .ok()?;
+        match inner.recv_or_stopped(&mut connection.receiver).await {
+            Some(NetworkMessage::Hello(peer_version)) => {
+                if peer_version != WIRE_VERSION {
+                    inner
+                        .metrics
+                        .handshake_rejections
+                        .with_label_values(&["wire_version"])
+                        .inc();
+                    return None;
+                }
+            }
+            _ => {
+                inner
+                    .metrics
+                    .handshake_rejections
+                    .with_label_values(&["before_hello"])
+                    .inc();
+                return None;
+            }
+        }
+
+        let (committee_epoch, authority) = {
+            let syncer = inner.syncer.read();
+            (syncer.committee().epoch(), syncer.core().authority())
+        };
+        connection
+            .sender
+            .send(NetworkMessage::HelloDetails {
+                committee_epoch,
+                authority,
+            })
+            .await
+            .ok()?;
+        match inner.recv_or_stopped(&mut connection.receiver).await {
+            Some(NetworkMessage::HelloDetails {
+                committee_epoch: peer_epoch,
+                ..
+            }) => {
+                if peer_epoch != committee_epoch {
+                    inner
+                        .metrics
+                        .handshake_rejections
+                        .with_label_values(&["committee_epoch"])
+                        .inc();
+                    return None;
+                }
+                Some(())
+            }
+            _ => {
+                inner
+                    .metrics
+                    .handshake_rejections
+                    .with_label_values(&["before_hello_details"])
+                    .inc();
+                None
+            }
+        }
+    }
+
     async fn send_blocks(
         to: mpsc::Sender<NetworkMessage>,
         inner: Arc<NetworkSyncerInner<H, C>>,
         mut round: RoundNumber,
+        peer: String,
     ) -> Option<()> {
+        let mut tranquilizer = Tranquilizer::new(inner.tranquility);
+        let sent_counter = inner.metrics.blocks_sent.with_label_values(&[&peer]);
         loop {
             let notified = inner.notify.notified();
-            let blocks = inner.syncer.read().get_own_blocks(round, 10);
+            let batch_start = Instant::now();
+            let blocks = inner.syncer.read().get_own_blocks(round, BATCH_SIZE);
+            let blocks_len = blocks.len();
             for block in blocks {
                 round = block.round();
+                inner.metrics.bytes_disseminated.inc_by(block.serialized_size() as u64);
                 to.send(NetworkMessage::Block(block)).await.ok()?;
+                sent_counter.inc();
+            }
+            tranquilizer
+                .tranquilize(batch_start.elapsed(), blocks_len)
+                .await;
+            select! {
+                _notified = notified => {}
+                _stopped = inner.stopped() => return None,
+            }
+        }
+    }
+
+    /// Keep `NetworkMetrics::benchmark_duration` up to date so the
+    /// orchestrator's `benchmark_duration` scrape reflects how long this node
+    /// has been disseminating blocks, not just a single point-in-time sample.
+    async fn benchmark_duration_task(inner: Arc<NetworkSyncerInner<H, C>>) -> Option<()> {
+        let started = Instant::now();
+        loop {
+            select! {
+                _tick = tokio::time::sleep(Duration::from_secs(1)) => {
+                    inner
+                        .metrics
+                        .benchmark_duration
+                        .set(started.elapsed().as_secs_f64());
+                }
+                _stopped = inner.stopped() => {
+                    return None;
+                }
             }
-            notified.await
         }
     }
 
@@ -148,7 +502,7 @@ impl<H: BlockHandler + 'static, C: CommitObserver + 'static> NetworkSyncer<H, C>
                 .unwrap_or_default();
             select! {
                 _sleep = tokio::time::sleep(leader_timeout) => {
-                    println!("Timeout");
+                    inner.metrics.leader_timeouts.inc();
                     inner.syncer.write().force_new_block(round);
                 }
                 _notified = notified => {
@@ -182,12 +536,118 @@ impl<H: BlockHandler + 'static, C: CommitObserver + 'static> NetworkSyncerInner<
     }
 }
 
+/// Paces [`NetworkSyncer::send_blocks`] so that a slow peer, or a burst of
+/// catch-up traffic, does not saturate the connection or the local CPU: after
+/// each batch it sleeps for `tranquility * average_recent_batch_duration`.
+/// A ring buffer of recent batch durations smooths the estimate so a single
+/// slow batch does not over-correct.
+struct Tranquilizer {
+    tranquility: f64,
+    recent_batch_durations: VecDeque<Duration>,
+}
+
+impl Tranquilizer {
+    const WINDOW: usize = 10;
+
+    fn new(tranquility: f64) -> Self {
+        Self {
+            tranquility,
+            recent_batch_durations: VecDeque::with_capacity(Self::WINDOW),
+        }
+    }
+
+    async fn tranquilize(&mut self, batch_duration: Duration, blocks_in_batch: usize) {
+        if self.recent_batch_durations.len() == Self::WINDOW {
+            self.recent_batch_durations.pop_front();
+        }
+        self.recent_batch_durations.push_back(batch_duration);
+        // Near the head of the subscription fewer blocks than a full batch
+        // come back; don't throttle catch-up traffic that is already caught up.
+        if blocks_in_batch < BATCH_SIZE {
+            return;
+        }
+        let average = self.recent_batch_durations.iter().sum::<Duration>()
+            / self.recent_batch_durations.len() as u32;
+        tokio::time::sleep(average.mul_f64(self.tranquility)).await;
+    }
+}
+
+#[cfg(test)]
+mod tranquilizer_tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn skips_throttling_below_batch_size() {
+        let mut tranquilizer = Tranquilizer::new(1.0);
+        let start = Instant::now();
+        tranquilizer
+            .tranquilize(Duration::from_millis(100), BATCH_SIZE - 1)
+            .await;
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn sleeps_proportional_to_recent_average() {
+        let mut tranquilizer = Tranquilizer::new(0.5);
+        tranquilizer
+            .tranquilize(Duration::from_millis(100), BATCH_SIZE)
+            .await;
+        let start = Instant::now();
+        tranquilizer
+            .tranquilize(Duration::from_millis(100), BATCH_SIZE)
+            .await;
+        assert_eq!(start.elapsed(), Duration::from_millis(50));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn window_caps_history() {
+        let mut tranquilizer = Tranquilizer::new(0.0);
+        for _ in 0..(Tranquilizer::WINDOW + 5) {
+            tranquilizer
+                .tranquilize(Duration::from_millis(10), BATCH_SIZE)
+                .await;
+        }
+        assert_eq!(tranquilizer.recent_batch_durations.len(), Tranquilizer::WINDOW);
+    }
+}
+
 impl SyncerSignals for Arc<Notify> {
     fn new_block_ready(&mut self) {
         self.notify_waiters();
     }
 }
 
+/// Wraps a caller-supplied [`CommitObserver`] to record
+/// `NetworkMetrics::latency_s`/`latency_squared_s` for every block as it
+/// commits, so the orchestrator's benchmark latency scrape is populated
+/// without every `CommitObserver` impl needing to know about metrics.
+///
+/// Public (rather than private) because it appears in
+/// [`NetworkSyncer::shutdown`]'s return type: `NetworkSyncer::start` wraps
+/// the observer passed in, so the `Syncer` handed back on shutdown is
+/// wrapped too.
+pub struct MetricsCommitObserver<C> {
+    inner: C,
+    metrics: Arc<NetworkMetrics>,
+}
+
+impl<C: CommitObserver> CommitObserver for MetricsCommitObserver<C> {
+    fn handle_commit(&mut self, committed: Vec<Data<StatementBlock>>) {
+        for block in &committed {
+            // `meta_creation_time` is the wall-clock time the block's author
+            // stamped it with before broadcasting, so this measures latency
+            // across the network, not just within this process.
+            let latency = SystemTime::now()
+                .duration_since(block.meta_creation_time())
+                .unwrap_or_default()
+                .as_secs_f64();
+            self.metrics.latency_s.observe(latency);
+            self.metrics.latency_squared_s.inc_by(latency * latency);
+        }
+        self.inner.handle_commit(committed);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::v2::test_util::{check_commits, network_syncers};
@@ -207,4 +667,4 @@ mod tests {
 
         check_commits(&syncers);
     }
-}
\ No newline at end of file
+}
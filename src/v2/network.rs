@@ -0,0 +1,62 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Wire types and connection plumbing shared by every peer link that
+//! [`crate::v2::net_sync::NetworkSyncer`] drives. `Network` hands out one
+//! [`Connection`] per established peer; `NetworkMessage` is everything that
+//! can flow over it.
+
+use crate::v2::data::Data;
+use crate::v2::types::{AuthorityIndex, RoundNumber, StatementBlock};
+use tokio::sync::mpsc;
+
+/// One established peer link: a channel pair plus the peer's authority
+/// index, handed to [`crate::v2::net_sync::NetworkSyncer::run`] as soon as
+/// the transport accepts the connection.
+pub struct Connection {
+    pub peer_id: usize,
+    pub sender: mpsc::Sender<NetworkMessage>,
+    pub receiver: mpsc::Receiver<NetworkMessage>,
+}
+
+/// Accepts inbound/outbound peer connections and surfaces each one as a
+/// [`Connection`].
+pub struct Network {
+    connection_receiver: mpsc::Receiver<Connection>,
+}
+
+impl Network {
+    pub fn connection_receiver(&mut self) -> &mut mpsc::Receiver<Connection> {
+        &mut self.connection_receiver
+    }
+}
+
+/// Everything that can be sent between two [`NetworkSyncer`](crate::v2::net_sync::NetworkSyncer)
+/// peers.
+pub enum NetworkMessage {
+    /// First half of the handshake: the sender's wire version as a single
+    /// byte, exchanged before any other field so a peer running an
+    /// incompatible version is rejected without attempting to deserialize a
+    /// payload it may encode differently.
+    Hello(u8),
+    /// Second half of the handshake, sent only after both sides have
+    /// accepted each other's `Hello`.
+    HelloDetails {
+        committee_epoch: u64,
+        authority: AuthorityIndex,
+    },
+    /// Ask the peer to start streaming its own blocks from `round` onward.
+    SubscribeOwnFrom(RoundNumber),
+    /// A single block, pushed as part of a subscription. A `RequestBlocks`
+    /// is answered with a `BlocksResponse` instead.
+    Block(Data<StatementBlock>),
+    /// Ask the peer to fill a gap: every block by `authority` with round in
+    /// `(from_round, to_round]`.
+    RequestBlocks {
+        authority: AuthorityIndex,
+        from_round: RoundNumber,
+        to_round: RoundNumber,
+    },
+    /// Reply to a `RequestBlocks`.
+    BlocksResponse(Vec<Data<StatementBlock>>),
+}
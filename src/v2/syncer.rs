@@ -0,0 +1,102 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The round-by-round block assembly and commit-rule loop, wrapped by
+//! [`crate::v2::net_sync::NetworkSyncer`] to drive it from network events.
+
+use crate::v2::block_handler::BlockHandler;
+use crate::v2::committee::Committee;
+use crate::v2::core::Core;
+use crate::v2::data::Data;
+use crate::v2::types::{AuthorityIndex, BlockReference, RoundNumber, StatementBlock};
+
+/// Notified whenever [`Syncer`] produces a new own block, so a driver (e.g.
+/// [`crate::v2::net_sync::NetworkSyncer`]'s `send_blocks` task) can wake up
+/// and disseminate it instead of polling.
+pub trait SyncerSignals {
+    fn new_block_ready(&mut self);
+}
+
+/// Notified whenever a round of blocks commits, so a driver can persist or
+/// execute the committed sequence.
+pub trait CommitObserver {
+    fn handle_commit(&mut self, committed: Vec<Data<StatementBlock>>);
+}
+
+pub struct Syncer<H, S, C> {
+    core: Core<H>,
+    commit_period: u64,
+    signals: S,
+    commit_observer: C,
+}
+
+impl<H: BlockHandler, S: SyncerSignals, C: CommitObserver> Syncer<H, S, C> {
+    pub fn new(core: Core<H>, commit_period: u64, signals: S, commit_observer: C) -> Self {
+        Self {
+            core,
+            commit_period,
+            signals,
+            commit_observer,
+        }
+    }
+
+    pub fn committee(&self) -> &Committee {
+        self.core.committee()
+    }
+
+    pub fn core(&self) -> &Core<H> {
+        &self.core
+    }
+
+    /// Force the creation of a new own block for `round` even if the commit
+    /// rule has not otherwise triggered one, e.g. after the leader timeout
+    /// fires.
+    pub fn force_new_block(&mut self, round: RoundNumber) {
+        if self.core.add_own_block(round) {
+            self.signals.new_block_ready();
+            self.try_commit();
+        }
+    }
+
+    /// Add blocks received from a peer to the DAG, returning every
+    /// reference they point to that we don't have yet so the caller (e.g.
+    /// [`crate::v2::net_sync::NetworkSyncer::connection_task`]) can request
+    /// it from whichever peer sent us the gap.
+    pub fn add_blocks(&mut self, blocks: Vec<Data<StatementBlock>>) -> Vec<BlockReference> {
+        let missing = self.core.add_blocks(blocks);
+        if !missing.is_empty() {
+            return missing;
+        }
+        self.signals.new_block_ready();
+        self.try_commit();
+        missing
+    }
+
+    fn try_commit(&mut self) {
+        let committed = self.core.try_commit(self.commit_period);
+        if !committed.is_empty() {
+            self.commit_observer.handle_commit(committed);
+        }
+    }
+
+    pub fn get_own_blocks(&self, round: RoundNumber, amount: usize) -> Vec<Data<StatementBlock>> {
+        self.core.get_own_blocks(round, amount)
+    }
+
+    pub fn get_blocks_by_authority(
+        &self,
+        authority: AuthorityIndex,
+        from_round: RoundNumber,
+        to_round: RoundNumber,
+    ) -> Vec<Data<StatementBlock>> {
+        self.core.get_blocks_by_authority(authority, from_round, to_round)
+    }
+
+    pub fn last_seen_by_authority(&self, authority: AuthorityIndex) -> RoundNumber {
+        self.core.last_seen_by_authority(authority)
+    }
+
+    pub fn last_own_block(&self) -> Option<Data<StatementBlock>> {
+        self.core.last_own_block()
+    }
+}